@@ -57,13 +57,23 @@ use core::{
     ptr,
 };
 
+#[cfg(feature = "std")]
+use core::slice;
+
 #[cfg(feature = "std")]
 use std::{error::Error, io};
 
+#[cfg(feature = "std")]
+use winapi::shared::winerror::{
+    ERROR_ACCESS_DENIED, ERROR_ALREADY_EXISTS, ERROR_BROKEN_PIPE, ERROR_FILE_EXISTS,
+    ERROR_FILE_NOT_FOUND, ERROR_INVALID_PARAMETER, ERROR_NOT_ENOUGH_MEMORY, ERROR_NO_DATA,
+    ERROR_OPERATION_ABORTED, ERROR_OUTOFMEMORY, ERROR_PATH_NOT_FOUND, ERROR_TIMEOUT, WAIT_TIMEOUT,
+};
+
 use winapi::{
-    shared::minwindef::DWORD,
+    shared::{minwindef::DWORD, ntdef::LANGID},
     um::{
-        errhandlingapi::GetLastError,
+        errhandlingapi::{GetLastError, SetLastError},
         winbase::{
             FormatMessageW, FORMAT_MESSAGE_FROM_SYSTEM, FORMAT_MESSAGE_IGNORE_INSERTS,
             FORMAT_MESSAGE_MAX_WIDTH_MASK,
@@ -71,6 +81,9 @@ use winapi::{
     },
 };
 
+#[cfg(feature = "std")]
+use winapi::um::winbase::{LocalFree, FORMAT_MESSAGE_ALLOCATE_BUFFER};
+
 /// A wrapper over Win32 API errors.
 /// Implements [`Display`] using [`FormatMessageW`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew).
 #[repr(transparent)]
@@ -90,11 +103,106 @@ impl Win32Error {
         Self::new(unsafe { GetLastError() })
     }
 
+    /// Sets this error code as the last error code for the current thread via
+    /// [`SetLastError`](https://docs.microsoft.com/en-us/windows/win32/api/errhandlingapi/nf-errhandlingapi-setlasterror).
+    ///
+    /// This is the counterpart to [`Win32Error::get_last_error`] and is useful for FFI shims that
+    /// emulate Win32 calling conventions and need to propagate an error code back to the caller.
+    pub fn set_last_error(self) {
+        unsafe { SetLastError(self.0) }
+    }
+
     /// Returns the underlying error code.
     #[must_use]
     pub const fn code(&self) -> DWORD {
         self.0
     }
+
+    /// Returns an object that formats this error's message in the language identified by `langid`.
+    ///
+    /// The `langid` is a [`MAKELANGID`](https://docs.microsoft.com/en-us/windows/win32/api/winnt/nf-winnt-makelangid)-style
+    /// identifier passed through to [`FormatMessageW`](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew)
+    /// as its `dwLanguageId`. If the requested language is not available the message is looked up in
+    /// the system default language (`0`) instead, so that a localized machine still yields a message
+    /// rather than the raw error code.
+    #[must_use]
+    pub const fn format_with_language(&self, langid: LANGID) -> impl Display {
+        DisplayWithLanguage {
+            error: *self,
+            langid,
+        }
+    }
+
+    /// Formats this error's message in the language identified by `langid` into a new [`String`].
+    ///
+    /// See [`Win32Error::format_with_language`] for details on the language fallback behavior.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn to_string_lang(&self, langid: LANGID) -> String {
+        self.format_with_language(langid).to_string()
+    }
+
+    /// Classifies the underlying error code into a [`std::io::ErrorKind`].
+    ///
+    /// The mapping follows the one used by the standard library's Windows backend, so that callers
+    /// can branch on error categories without re-deriving it. Codes with no specific category map to
+    /// [`io::ErrorKind::Other`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub const fn kind(&self) -> io::ErrorKind {
+        use io::ErrorKind;
+
+        match self.0 {
+            ERROR_ACCESS_DENIED => ErrorKind::PermissionDenied,
+            ERROR_FILE_NOT_FOUND | ERROR_PATH_NOT_FOUND => ErrorKind::NotFound,
+            ERROR_ALREADY_EXISTS | ERROR_FILE_EXISTS => ErrorKind::AlreadyExists,
+            ERROR_BROKEN_PIPE | ERROR_NO_DATA => ErrorKind::BrokenPipe,
+            ERROR_OPERATION_ABORTED => ErrorKind::Interrupted,
+            ERROR_TIMEOUT | WAIT_TIMEOUT => ErrorKind::TimedOut,
+            ERROR_INVALID_PARAMETER => ErrorKind::InvalidInput,
+            ERROR_NOT_ENOUGH_MEMORY | ERROR_OUTOFMEMORY => ErrorKind::OutOfMemory,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Returns `true` if this is an application-defined error, i.e. bit 29 (the customer bit) is set.
+    ///
+    /// The system message table does not contain descriptions for such codes, so [`Display`] emits
+    /// a raw hexadecimal form for them rather than calling [`FormatMessageW`].
+    #[must_use]
+    pub const fn is_application_error(&self) -> bool {
+        self.0 & (1 << 29) != 0
+    }
+
+    /// Returns the severity field (bits 31–30) of the code interpreted as an `HRESULT`.
+    #[must_use]
+    pub const fn severity(&self) -> DWORD {
+        (self.0 >> 30) & 0b11
+    }
+
+    /// Returns the facility field (bits 27–16) of the code interpreted as an `HRESULT`.
+    #[must_use]
+    pub const fn facility(&self) -> DWORD {
+        (self.0 >> 16) & 0xFFF
+    }
+
+    /// Returns the code field (bits 15–0) of the code interpreted as an `HRESULT`.
+    #[must_use]
+    pub const fn code_part(&self) -> DWORD {
+        self.0 & 0xFFFF
+    }
+
+    /// Returns `true` if the severity bit (bit 31) is set, indicating a failure `HRESULT`.
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        self.0 & (1 << 31) != 0
+    }
+
+    /// Returns `true` if the severity bit (bit 31) is clear, indicating a success `HRESULT`.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        !self.is_failure()
+    }
 }
 
 impl From<DWORD> for Win32Error {
@@ -111,52 +219,170 @@ impl From<Win32Error> for DWORD {
 
 impl Display for Win32Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut buf = maybe_uninit_uninit_array::<u16, 1024>();
-
-        let len = unsafe {
-            FormatMessageW(
-                FORMAT_MESSAGE_FROM_SYSTEM
-                    | FORMAT_MESSAGE_IGNORE_INSERTS
-                    | FORMAT_MESSAGE_MAX_WIDTH_MASK,
-                ptr::null(),
-                self.0,
-                0,
-                buf[0].as_mut_ptr(),
-                buf.len() as _,
-                ptr::null_mut(),
-            )
-        } as usize;
-
-        if len == 0 {
-            // `FormatMessageW` failed -> use raw error code instead
-            write!(f, "{:#08X}", self.0)
-        } else {
-            // `FormatMessageW` succeeded -> convert to UTF8 and process
-            let wide_chars = unsafe { maybe_uninit_slice_assume_init_ref(&buf[..len]) };
-            let mut char_buf = maybe_uninit_uninit_array::<char, 1024>();
-
-            let char_iter = char::decode_utf16(wide_chars.iter().copied())
-                .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER));
-
-            let mut i = 0;
-            for c in char_iter {
-                char_buf[i].write(c);
-                i += 1;
-            }
-
-            let chars = unsafe { maybe_uninit_slice_assume_init_ref(&char_buf[..i]) };
-            let start = chars.iter().position(|c| !c.is_whitespace()).unwrap_or(0);
-            let end = chars
-                .iter()
-                .rposition(|c| !c.is_whitespace())
-                .unwrap_or(chars.len());
-            for c in &chars[start..end] {
-                f.write_char(*c)?;
-            }
+        if self.is_application_error() {
+            // application-defined codes are not in the system message table
+            return write!(f, "Application error: {:#010X}", self.0);
+        }
+        fmt_message(self.0, 0, f)
+    }
+}
 
-            Ok(())
+/// The [`Display`] adapter returned by [`Win32Error::format_with_language`].
+#[derive(Debug, Copy, Clone)]
+struct DisplayWithLanguage {
+    error: Win32Error,
+    langid: LANGID,
+}
+
+impl Display for DisplayWithLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.error.is_application_error() {
+            // application-defined codes are not in the system message table
+            return write!(f, "Application error: {:#010X}", self.error.0);
+        }
+        fmt_message(self.error.0, self.langid, f)
+    }
+}
+
+/// Formats the message for `code` in the language identified by `langid`, falling back to the
+/// system default language (`0`) if the requested language is unavailable.
+fn fmt_message(code: DWORD, langid: LANGID, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // Try the requested language first (including the long-message growth path under `std`) before
+    // falling back to the system default language, so that a message too long for the stack buffer in
+    // the requested language does not silently return the default-language variant instead.
+    if let Some(result) = fmt_message_in(code, langid, f) {
+        return result;
+    }
+    if langid != 0 {
+        // the requested language is unavailable -> retry in the system default language
+        if let Some(result) = fmt_message_in(code, 0, f) {
+            return result;
         }
     }
+
+    // `FormatMessageW` failed -> use raw error code instead
+    write!(f, "{:#08X}", code)
+}
+
+/// Formats the message for `code` in exactly `langid`, using the 1024-wide-char stack fast path and,
+/// under `std`, growing to an OS-allocated buffer when the message does not fit. Returns [`None`] if
+/// no message is available for `code` in that language.
+fn fmt_message_in(code: DWORD, langid: LANGID, f: &mut fmt::Formatter<'_>) -> Option<fmt::Result> {
+    let mut buf = maybe_uninit_uninit_array::<u16, 1024>();
+
+    let len = unsafe { format_message_into(code, langid, &mut buf) };
+    if len != 0 {
+        // `FormatMessageW` succeeded -> convert to UTF8 and process
+        let wide_chars = unsafe { maybe_uninit_slice_assume_init_ref(&buf[..len]) };
+        return Some(write_trimmed(wide_chars, f));
+    }
+
+    // The stack buffer was either too small for the message or the code has no system message in this
+    // language. Under `std` we retry with an OS-allocated buffer so that messages longer than the
+    // 1024-wide-char fast path are not truncated.
+    #[cfg(feature = "std")]
+    {
+        fmt_message_allocated(code, langid, f)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        None
+    }
+}
+
+/// Formats the message for `code` in `langid` by letting [`FormatMessageW`] allocate a correctly
+/// sized buffer via `FORMAT_MESSAGE_ALLOCATE_BUFFER`, used as the growth path when the 1024-wide-char
+/// stack buffer is too small. Returns [`None`] if no message is available for `code`.
+#[cfg(feature = "std")]
+fn fmt_message_allocated(code: DWORD, langid: LANGID, f: &mut fmt::Formatter<'_>) -> Option<fmt::Result> {
+    let mut ptr: *mut u16 = ptr::null_mut();
+
+    let len = unsafe { format_message_allocate(code, langid, &mut ptr) };
+    if len == 0 {
+        return None;
+    }
+
+    let wide_chars = unsafe { slice::from_raw_parts(ptr, len) };
+    let result = write_trimmed(wide_chars, f);
+    // `FormatMessageW` allocated the buffer with `LocalAlloc` -> free it again.
+    unsafe { LocalFree(ptr.cast()) };
+    Some(result)
+}
+
+/// Writes the UTF-16 `wide_chars` to `f` with surrounding whitespace trimmed.
+fn write_trimmed(wide_chars: &[u16], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // Whitespace characters are all in the BMP (single UTF-16 units), so the leading and trailing
+    // whitespace can be trimmed directly on the wide slice. Working on the units rather than buffering
+    // the decoded chars keeps this bounded by the input length, which the allocated long-message path
+    // relies on (its slice may be longer than the 1024-wide-char stack buffer).
+    let is_whitespace =
+        |&unit: &u16| char::from_u32(unit as u32).is_some_and(|c| c.is_whitespace());
+    let trimmed = match (
+        wide_chars.iter().position(|u| !is_whitespace(u)),
+        wide_chars.iter().rposition(|u| !is_whitespace(u)),
+    ) {
+        (Some(start), Some(end)) => &wide_chars[start..=end],
+        // the message is empty or all-whitespace
+        _ => &[],
+    };
+
+    let char_iter = char::decode_utf16(trimmed.iter().copied())
+        .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER));
+    for c in char_iter {
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+/// Calls [`FormatMessageW`] for `code` in `langid`, writing into `buf` and returning the number of
+/// wide characters written (`0` on failure).
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `buf.len()` wide characters.
+unsafe fn format_message_into(code: DWORD, langid: LANGID, buf: &mut [MaybeUninit<u16>]) -> usize {
+    unsafe {
+        FormatMessageW(
+            FORMAT_MESSAGE_FROM_SYSTEM
+                | FORMAT_MESSAGE_IGNORE_INSERTS
+                | FORMAT_MESSAGE_MAX_WIDTH_MASK,
+            ptr::null(),
+            code,
+            langid as DWORD,
+            buf.as_mut_ptr().cast(),
+            buf.len() as _,
+            ptr::null_mut(),
+        ) as usize
+    }
+}
+
+/// Calls [`FormatMessageW`] for `code` in `langid` with `FORMAT_MESSAGE_ALLOCATE_BUFFER`, storing
+/// the pointer to the OS-allocated buffer in `out` and returning the number of wide characters
+/// written (`0` on failure). The buffer must be released with [`LocalFree`] by the caller.
+///
+/// # Safety
+///
+/// On success `*out` points to a `LocalAlloc`-allocated buffer that the caller is responsible for
+/// freeing with [`LocalFree`].
+#[cfg(feature = "std")]
+unsafe fn format_message_allocate(code: DWORD, langid: LANGID, out: &mut *mut u16) -> usize {
+    unsafe {
+        FormatMessageW(
+            FORMAT_MESSAGE_FROM_SYSTEM
+                | FORMAT_MESSAGE_IGNORE_INSERTS
+                | FORMAT_MESSAGE_MAX_WIDTH_MASK
+                | FORMAT_MESSAGE_ALLOCATE_BUFFER,
+            ptr::null(),
+            code,
+            langid as DWORD,
+            // with `FORMAT_MESSAGE_ALLOCATE_BUFFER` the `lpBuffer` argument is reinterpreted as a
+            // `LPWSTR*` that receives the address of the allocated buffer.
+            (out as *mut *mut u16).cast(),
+            0,
+            ptr::null_mut(),
+        ) as usize
+    }
 }
 
 #[cfg(feature = "std")]
@@ -197,6 +423,60 @@ impl From<Win32Error> for io::Error {
     }
 }
 
+/// Extension trait for turning a raw Win32 FFI return value into a [`Result`].
+///
+/// Many Win32 functions signal failure through their return value (a `FALSE` boolean or a null
+/// pointer) and expose the actual cause through [`GetLastError`]. This trait couples the two so
+/// that callers can write `unsafe { SomeApi(...) }.or_last_error()?` instead of checking the return
+/// value and calling [`Win32Error::get_last_error`] by hand.
+///
+/// The trait is implemented for [`bool`] and raw pointers; the integer `BOOL` type returned by
+/// Win32 functions is not covered.
+pub trait LastErrorResultExt {
+    /// The value produced when the FFI result indicates success.
+    type Output;
+
+    /// Returns [`Ok`] with the success value if the FFI result indicates success, otherwise [`Err`]
+    /// with the current thread's last error code.
+    fn or_last_error(self) -> Result<Self::Output, Win32Error>;
+}
+
+impl LastErrorResultExt for bool {
+    type Output = ();
+
+    fn or_last_error(self) -> Result<Self::Output, Win32Error> {
+        if self {
+            Ok(())
+        } else {
+            Err(Win32Error::get_last_error())
+        }
+    }
+}
+
+impl<T> LastErrorResultExt for *mut T {
+    type Output = *mut T;
+
+    fn or_last_error(self) -> Result<Self::Output, Win32Error> {
+        if self.is_null() {
+            Err(Win32Error::get_last_error())
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl<T> LastErrorResultExt for *const T {
+    type Output = *const T;
+
+    fn or_last_error(self) -> Result<Self::Output, Win32Error> {
+        if self.is_null() {
+            Err(Win32Error::get_last_error())
+        } else {
+            Ok(self)
+        }
+    }
+}
+
 const unsafe fn maybe_uninit_slice_assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
     #[cfg(nightly)]
     unsafe {